@@ -1,9 +1,11 @@
-use std::{fs, io, path::PathBuf};
+use std::{fs, path::PathBuf, sync::mpsc::Receiver, time::Duration};
 
 use color_eyre::eyre::{Context, Result};
 use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
-use project_library::{CycleDirection, ProjectLibrary};
-use ratatui::{layout::{Constraint, Direction, Layout}, style::Stylize, text::{Line, Text}, widgets::{Block, BorderType, Paragraph}, DefaultTerminal, Frame};
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use project_library::{CycleDirection, Project, ProjectLibrary};
+use ratatui::{layout::{Constraint, Direction, Flex, Layout, Rect}, style::{Color, Stylize}, text::{Line, Text}, widgets::{Block, BorderType, Clear, Paragraph}, DefaultTerminal, Frame};
+mod markdown;
 mod project_library;
 
 #[derive(PartialEq)]
@@ -11,27 +13,72 @@ enum AppState {
     MainView,
     DeletingProject,
     AddingProject,
+    Searching,
     Exiting
 }
 
+#[derive(PartialEq)]
+enum FormField {
+    Name,
+    Description
+}
+
+struct ProjectForm {
+    name: String,
+    description: String,
+    focused_field: FormField
+}
+
+impl ProjectForm {
+    fn new() -> Self {
+        ProjectForm {
+            name: String::new(),
+            description: String::new(),
+            focused_field: FormField::Name
+        }
+    }
+
+    fn toggle_focus(&mut self) {
+        self.focused_field = match self.focused_field {
+            FormField::Name => FormField::Description,
+            FormField::Description => FormField::Name
+        };
+    }
+
+    fn push_char(&mut self, c: char) {
+        match self.focused_field {
+            FormField::Name => self.name.push(c),
+            FormField::Description => self.description.push(c)
+        }
+    }
+
+    fn pop_char(&mut self) {
+        match self.focused_field {
+            FormField::Name => { self.name.pop(); },
+            FormField::Description => { self.description.pop(); }
+        }
+    }
+}
+
 struct App {
     project_library: ProjectLibrary,
-    state: AppState
+    state: AppState,
+    project_form: ProjectForm
 }
 
 impl App {
     fn new(project_library: ProjectLibrary) -> Self {
-        App { project_library, state: AppState::MainView }
+        App { project_library, state: AppState::MainView, project_form: ProjectForm::new() }
     }
 
     fn exit(&mut self) -> Result<()> {
         self.project_library.save()
     }
 
-    fn run(&mut self, terminal: &mut DefaultTerminal) -> Result<()> {
+    fn run(&mut self, terminal: &mut DefaultTerminal, fs_events: &Receiver<notify::Result<notify::Event>>) -> Result<()> {
         while self.state != AppState::Exiting {
             terminal.draw(|frame| self.draw(frame))?;
-            self.handle_events()?;
+            self.handle_events(fs_events)?;
         }
 
         self.exit()?;
@@ -50,43 +97,184 @@ impl App {
 
         let instructions_block = Block::bordered()
                 .border_type(BorderType::Double);
-        
+
         frame.render_widget(Paragraph::new(Text::from(Line::from(vec![
             " Scroll Up ".into(),
             "<Up> or <k>".blue().bold(),
             " Scroll Down ".into(),
             "<Down> or <j>".blue().bold(),
+            " Switch Tab ".into(),
+            "<Left>/<Right>".blue().bold(),
             " Add Project ".into(),
             "<A>".blue().bold(),
+            " Delete Project ".into(),
+            "<D>".blue().bold(),
+            " Undo Delete ".into(),
+            "<u>".blue().bold(),
+            " Search ".into(),
+            "</>".blue().bold(),
             " Quit ".into(),
             "<q>".blue().bold()
         ]))).block(instructions_block), chunks[1]);
+
+        match self.state {
+            AppState::AddingProject => self.draw_add_project_form(frame, chunks[0]),
+            AppState::DeletingProject => self.draw_delete_project_confirmation(frame, chunks[0]),
+            _ => {}
+        }
+    }
+
+    fn draw_add_project_form(&self, frame: &mut Frame, area: Rect) {
+        let popup_area = centered_rect(area, 50, 40);
+
+        let form_block = Block::bordered()
+            .border_type(BorderType::Double)
+            .title(" Add Project ".bold());
+
+        let inner_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Min(3)
+            ]).split(form_block.inner(popup_area));
+
+        let name_block = Block::bordered().title(" Name ");
+        let name_block = if self.project_form.focused_field == FormField::Name {
+            name_block.border_style(Color::Blue)
+        } else {
+            name_block
+        };
+
+        let description_block = Block::bordered().title(" Description ");
+        let description_block = if self.project_form.focused_field == FormField::Description {
+            description_block.border_style(Color::Blue)
+        } else {
+            description_block
+        };
+
+        frame.render_widget(Clear, popup_area);
+        frame.render_widget(&form_block, popup_area);
+        frame.render_widget(Paragraph::new(self.project_form.name.as_str()).block(name_block), inner_chunks[0]);
+        frame.render_widget(Paragraph::new(self.project_form.description.as_str()).wrap(ratatui::widgets::Wrap { trim: false }).block(description_block), inner_chunks[1]);
+    }
+
+    fn draw_delete_project_confirmation(&self, frame: &mut Frame, area: Rect) {
+        let popup_area = centered_rect(area, 40, 20);
+
+        let block = Block::bordered()
+            .border_type(BorderType::Double)
+            .title(" Delete Project ".bold());
+
+        let text = Text::from(Line::from(vec![
+            "Delete this project? ".into(),
+            "<Y>".red().bold(),
+            "es / ".into(),
+            "<N>".blue().bold(),
+            "o".into()
+        ]));
+
+        frame.render_widget(Clear, popup_area);
+        frame.render_widget(Paragraph::new(text).centered().block(block), popup_area);
     }
 
     fn handle_key_event(&mut self, key_event: KeyEvent) {
+        match self.state {
+            AppState::AddingProject => self.handle_adding_project_key_event(key_event),
+            AppState::DeletingProject => self.handle_deleting_project_key_event(key_event),
+            AppState::Searching => self.handle_searching_key_event(key_event),
+            _ => self.handle_main_view_key_event(key_event)
+        }
+    }
+
+    fn handle_main_view_key_event(&mut self, key_event: KeyEvent) {
         match key_event.code {
             KeyCode::Char('q') => self.state = AppState::Exiting,
             KeyCode::Up | KeyCode::Char('k') => self.project_library.cycle_selected_project(CycleDirection::Up),
             KeyCode::Down | KeyCode::Char('j') => self.project_library.cycle_selected_project(CycleDirection::Down),
             KeyCode::Char(' ') => self.project_library.cycle_selected_project_status(CycleDirection::Up),
-            KeyCode::Char('A') => self.state = AppState::AddingProject,
-            KeyCode::Char('D') => self.state = AppState::DeletingProject,
+            KeyCode::Char('A') => {
+                self.project_form = ProjectForm::new();
+                self.state = AppState::AddingProject;
+            },
+            KeyCode::Char('D') if self.project_library.has_selected_project() => {
+                self.state = AppState::DeletingProject;
+            },
+            KeyCode::Char('/') => self.state = AppState::Searching,
+            KeyCode::Left => self.project_library.cycle_status_filter(CycleDirection::Up),
+            KeyCode::Right => self.project_library.cycle_status_filter(CycleDirection::Down),
+            KeyCode::Char('u') if self.project_library.has_recently_deleted() => self.project_library.undo_delete(),
+            _ => {}
+        }
+    }
+
+    fn handle_searching_key_event(&mut self, key_event: KeyEvent) {
+        match key_event.code {
+            KeyCode::Esc => {
+                self.project_library.clear_filter();
+                self.state = AppState::MainView;
+            },
+            KeyCode::Enter => self.state = AppState::MainView,
+            KeyCode::Backspace => self.project_library.pop_filter_char(),
+            KeyCode::Char(c) => self.project_library.push_filter_char(c),
+            _ => {}
+        }
+    }
+
+    fn handle_adding_project_key_event(&mut self, key_event: KeyEvent) {
+        match key_event.code {
             KeyCode::Esc => self.state = AppState::MainView,
+            KeyCode::Tab => self.project_form.toggle_focus(),
+            KeyCode::Backspace => self.project_form.pop_char(),
+            KeyCode::Char(c) => self.project_form.push_char(c),
+            KeyCode::Enter => {
+                let name = self.project_form.name.trim();
+                if !name.is_empty() {
+                    self.project_library.add_project(Project::new(name, &self.project_form.description));
+                    self.state = AppState::MainView;
+                }
+            },
             _ => {}
         }
     }
 
-    fn handle_events(&mut self) -> io::Result<()> {
-        match event::read()? {
-           Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
-            self.handle_key_event(key_event)
-           } 
+    fn handle_deleting_project_key_event(&mut self, key_event: KeyEvent) {
+        match key_event.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => {
+                self.project_library.delete_selected_project();
+                self.state = AppState::MainView;
+            },
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => self.state = AppState::MainView,
             _ => {}
-        };
+        }
+    }
+
+    fn handle_events(&mut self, fs_events: &Receiver<notify::Result<notify::Event>>) -> Result<()> {
+        if event::poll(Duration::from_millis(100))?
+            && let Event::Key(key_event) = event::read()?
+            && key_event.kind == KeyEventKind::Press
+        {
+            self.handle_key_event(key_event);
+        }
+
+        for fs_event in fs_events.try_iter().flatten() {
+            let changed_on_disk = fs_event.kind.is_modify() || fs_event.kind.is_create();
+            if changed_on_disk && !self.project_library.was_recently_saved() {
+                self.project_library.reload_from_disk()?;
+            }
+        }
+
         Ok(())
     }
 }
 
+fn centered_rect(area: Rect, percent_x: u16, percent_y: u16) -> Rect {
+    let vertical = Layout::vertical([Constraint::Percentage(percent_y)]).flex(Flex::Center);
+    let horizontal = Layout::horizontal([Constraint::Percentage(percent_x)]).flex(Flex::Center);
+    let [area] = vertical.areas(area);
+    let [area] = horizontal.areas(area);
+    area
+}
+
 fn main() -> Result<()> {
     color_eyre::install()?;
 
@@ -107,9 +295,15 @@ fn main() -> Result<()> {
 
     let project_library = ProjectLibrary::from_file(&library_file_path)?;
 
+    let (fs_event_tx, fs_event_rx) = std::sync::mpsc::channel();
+    let mut watcher: RecommendedWatcher = notify::recommended_watcher(move |result| {
+        let _ = fs_event_tx.send(result);
+    })?;
+    watcher.watch(&library_file_path, RecursiveMode::NonRecursive)?;
+
     let mut terminal = ratatui::init();
     terminal.clear()?;
-    let app_result = App::new(project_library).run(&mut terminal);
+    let app_result = App::new(project_library).run(&mut terminal, &fs_event_rx);
     ratatui::restore();
     app_result.wrap_err("App Error")
 }
\ No newline at end of file
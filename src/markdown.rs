@@ -0,0 +1,104 @@
+use pulldown_cmark::{Event, Parser, Tag, TagEnd};
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span, Text}
+};
+
+/// Renders a Markdown string into a styled ratatui `Text`: headings are bold,
+/// `**bold**`/`*italic*` spans keep their styling, bullet list items get a `•`
+/// prefix with indentation for nesting, and code is styled dim/gray. Line
+/// wrapping to the target pane width is left to the caller's `Paragraph::wrap`.
+pub fn render_markdown(source: &str) -> Text<'static> {
+    let mut lines: Vec<Line<'static>> = Vec::new();
+    let mut current_line: Vec<Span<'static>> = Vec::new();
+    let mut style_stack: Vec<Style> = vec![Style::default()];
+    let mut list_depth: usize = 0;
+
+    let flush_line = |lines: &mut Vec<Line<'static>>, current_line: &mut Vec<Span<'static>>| {
+        lines.push(Line::from(std::mem::take(current_line)));
+    };
+
+    for event in Parser::new(source) {
+        match event {
+            Event::Start(Tag::Heading { .. }) => {
+                if !current_line.is_empty() {
+                    flush_line(&mut lines, &mut current_line);
+                }
+                style_stack.push(style_stack.last().copied().unwrap_or_default().add_modifier(Modifier::BOLD));
+            },
+            Event::Start(Tag::Strong) => {
+                style_stack.push(style_stack.last().copied().unwrap_or_default().add_modifier(Modifier::BOLD));
+            },
+            Event::Start(Tag::Emphasis) => {
+                style_stack.push(style_stack.last().copied().unwrap_or_default().add_modifier(Modifier::ITALIC));
+            },
+            Event::Start(Tag::CodeBlock(_)) => {
+                if !current_line.is_empty() {
+                    flush_line(&mut lines, &mut current_line);
+                }
+                style_stack.push(Style::default().fg(Color::DarkGray));
+            },
+            Event::Start(Tag::List(_)) => {
+                list_depth += 1;
+            },
+            Event::Start(Tag::Item) => {
+                if !current_line.is_empty() {
+                    flush_line(&mut lines, &mut current_line);
+                }
+                current_line.push(Span::raw("  ".repeat(list_depth.saturating_sub(1))));
+                current_line.push(Span::raw("• "));
+            },
+            Event::Start(Tag::Paragraph) => {
+                if !current_line.is_empty() {
+                    flush_line(&mut lines, &mut current_line);
+                }
+            },
+            Event::End(TagEnd::Heading(_)) => {
+                style_stack.pop();
+                flush_line(&mut lines, &mut current_line);
+                lines.push(Line::default());
+            },
+            Event::End(TagEnd::Strong | TagEnd::Emphasis) => {
+                style_stack.pop();
+            },
+            Event::End(TagEnd::CodeBlock) => {
+                style_stack.pop();
+                flush_line(&mut lines, &mut current_line);
+            },
+            Event::End(TagEnd::List(_)) => {
+                list_depth = list_depth.saturating_sub(1);
+            },
+            Event::End(TagEnd::Item) => {
+                flush_line(&mut lines, &mut current_line);
+            },
+            Event::End(TagEnd::Paragraph) => {
+                flush_line(&mut lines, &mut current_line);
+                lines.push(Line::default());
+            },
+            Event::Text(text) => {
+                let style = *style_stack.last().unwrap_or(&Style::default());
+                current_line.push(Span::styled(text.into_string(), style));
+            },
+            Event::Code(text) => {
+                current_line.push(Span::styled(text.into_string(), Style::default().fg(Color::DarkGray)));
+            },
+            Event::SoftBreak => {
+                current_line.push(Span::raw(" "));
+            },
+            Event::HardBreak => {
+                flush_line(&mut lines, &mut current_line);
+            },
+            _ => {}
+        }
+    }
+
+    if !current_line.is_empty() {
+        flush_line(&mut lines, &mut current_line);
+    }
+
+    while lines.last().is_some_and(|line| line.spans.is_empty()) {
+        lines.pop();
+    }
+
+    Text::from(lines)
+}
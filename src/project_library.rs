@@ -1,16 +1,18 @@
 use core::fmt;
-use std::{fs, path::PathBuf};
+use std::{fs, path::PathBuf, time::{Duration, Instant}};
 
 use color_eyre::eyre::Result;
-use ratatui::{layout::{Alignment, Constraint, Direction, Layout, Rect}, style::{Color, Style, Stylize}, symbols, text::{Line, Span, Text}, widgets::{block::Title, Block, BorderType, Borders, List, ListItem, Padding, Paragraph, Widget}};
+use ratatui::{layout::{Alignment, Constraint, Direction, Layout, Rect}, style::{Color, Style, Stylize}, symbols, text::{Line, Span, Text}, widgets::{block::Title, Block, BorderType, Borders, List, ListItem, Padding, Paragraph, Tabs, Widget, Wrap}};
 use serde::{Deserialize, Serialize};
 
+use crate::markdown::render_markdown;
+
 pub enum CycleDirection {
     Up,
     Down
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all="snake_case")]
 pub enum ProjectStatus {
     Finished,
@@ -19,6 +21,15 @@ pub enum ProjectStatus {
     Paused,
 }
 
+/// `None` is the "All" tab; `Some(status)` narrows the list to that single status.
+const STATUS_FILTERS: [Option<ProjectStatus>; 5] = [
+    None,
+    Some(ProjectStatus::Finished),
+    Some(ProjectStatus::InProgress),
+    Some(ProjectStatus::Idea),
+    Some(ProjectStatus::Paused)
+];
+
 impl ProjectStatus {
     pub fn cycle(&self, direction: CycleDirection) -> Self {
         match direction {
@@ -38,12 +49,48 @@ impl ProjectStatus {
 
     }
 
-    pub fn to_symbol(&self) -> Span<'_> {
-        match self {
-            Self::Finished => "✔".green(),
-            Self::InProgress => "-".yellow(),
-            Self::Paused => "X".red(),
-            Self::Idea => "!".white()
+    pub fn to_symbol(&self, theme: &Theme) -> Span<'static> {
+        let (symbol, color) = match self {
+            Self::Finished => (theme.finished_symbol, theme.finished_color),
+            Self::InProgress => (theme.in_progress_symbol, theme.in_progress_color),
+            Self::Paused => (theme.paused_symbol, theme.paused_color),
+            Self::Idea => (theme.idea_symbol, theme.idea_color)
+        };
+
+        Span::styled(symbol.to_string(), Style::default().fg(color))
+    }
+}
+
+/// User-configurable colors and symbols, persisted under `[theme]` in the config file.
+/// Any field left out of the TOML falls back to its value in `Theme::default()`.
+#[derive(Clone, Copy, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Theme {
+    pub finished_color: Color,
+    pub finished_symbol: char,
+    pub in_progress_color: Color,
+    pub in_progress_symbol: char,
+    pub idea_color: Color,
+    pub idea_symbol: char,
+    pub paused_color: Color,
+    pub paused_symbol: char,
+    pub selection_background: Color,
+    pub border_color: Color
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme {
+            finished_color: Color::Green,
+            finished_symbol: '✔',
+            in_progress_color: Color::Yellow,
+            in_progress_symbol: '-',
+            idea_color: Color::White,
+            idea_symbol: '!',
+            paused_color: Color::Red,
+            paused_symbol: 'X',
+            selection_background: Color::Blue,
+            border_color: Color::Reset
         }
     }
 }
@@ -61,7 +108,7 @@ impl fmt::Display for ProjectStatus {
     }
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Project {
     name: String,
     description: String,
@@ -85,45 +132,200 @@ impl Project {
         self.status = self.status.cycle(direction);
     }
 
-    pub fn to_list_item(&self) -> ListItem {
-        let symbol = self.status.to_symbol();
-        let text = Text::from(format!("{} {}", symbol.content, self.name));
-        ListItem::new(text).style(symbol.style)
+    pub fn to_list_item(&self, matched_indices: &[usize], theme: &Theme) -> ListItem<'static> {
+        let symbol = self.status.to_symbol(theme);
+        let mut spans = vec![Span::raw(format!("{} ", symbol.content))];
+
+        for (index, name_char) in self.name.chars().enumerate() {
+            let span = Span::raw(name_char.to_string());
+            if matched_indices.contains(&index) {
+                spans.push(span.yellow().bold());
+            } else {
+                spans.push(span);
+            }
+        }
+
+        ListItem::new(Line::from(spans)).style(symbol.style)
+    }
+}
+
+/// Loads the undo stack written by `save_trash`, if `trash_file_path` exists, so
+/// soft-deleted projects survive a restart instead of being silently discarded.
+fn load_trash(trash_file_path: &PathBuf) -> Result<Vec<(usize, Project)>> {
+    if !trash_file_path.try_exists()? {
+        return Ok(Vec::new());
+    }
+
+    let file_string = fs::read_to_string(trash_file_path)?;
+    let trash = toml::from_str::<Trash>(&file_string)?;
+    Ok(trash.entries.into_iter().map(|entry| (entry.index, entry.project)).collect())
+}
+
+/// Matches `query` against `name` as a case-insensitive subsequence, returning a score
+/// (higher is better) and the indices of `name` that were matched, or `None` if `query`
+/// isn't a subsequence of `name` at all.
+fn fuzzy_match(query: &str, name: &str) -> Option<(i64, Vec<usize>)> {
+    if query.is_empty() {
+        return Some((0, Vec::new()));
+    }
+
+    let name_chars: Vec<char> = name.chars().collect();
+    let mut query_chars = query.chars();
+    let mut current_query_char = query_chars.next();
+
+    let mut matched_indices = Vec::new();
+    let mut last_matched_index: Option<usize> = None;
+    let mut score: i64 = 0;
+
+    for (index, &name_char) in name_chars.iter().enumerate() {
+        let Some(query_char) = current_query_char else { break };
+
+        if query_char.to_ascii_lowercase() != name_char.to_ascii_lowercase() {
+            continue;
+        }
+
+        let is_boundary = index == 0 || matches!(name_chars[index - 1], ' ' | '_' | '-');
+
+        score += 10;
+        if is_boundary {
+            score += 15;
+        }
+        match last_matched_index {
+            Some(previous) if previous + 1 == index => score += 20,
+            Some(previous) => score -= (index - previous) as i64,
+            None => score -= index as i64
+        }
+
+        matched_indices.push(index);
+        last_matched_index = Some(index);
+        current_query_char = query_chars.next();
+    }
+
+    if current_query_char.is_some() {
+        None
+    } else {
+        Some((score, matched_indices))
     }
 }
 
 #[derive(Default, Serialize, Deserialize)]
 pub struct ProjectLibrary {
     projects: Vec<Project>,
+    #[serde(default)]
+    theme: Theme,
     #[serde(skip_serializing, skip_deserializing)]
     selected_project_index: usize,
     #[serde(skip_serializing, skip_deserializing)]
-    library_file_path: PathBuf
+    library_file_path: PathBuf,
+    #[serde(skip_serializing, skip_deserializing)]
+    filter_query: String,
+    #[serde(skip_serializing, skip_deserializing)]
+    status_filter_index: usize,
+    #[serde(skip_serializing, skip_deserializing)]
+    last_saved_at: Option<Instant>,
+    #[serde(skip_serializing, skip_deserializing)]
+    trash_file_path: PathBuf,
+    #[serde(skip_serializing, skip_deserializing)]
+    recently_deleted: Vec<(usize, Project)>,
+    /// Whether `projects`/`recently_deleted` have changed since the last `save`. Guards
+    /// `reload_from_disk` against clobbering unsaved session edits with whatever is on disk.
+    #[serde(skip_serializing, skip_deserializing)]
+    dirty: bool
+}
+
+/// A single soft-deleted project paired with the index it should be reinserted at
+/// on `undo_delete`.
+#[derive(Serialize, Deserialize)]
+struct TrashEntry {
+    index: usize,
+    project: Project
+}
+
+/// Mirror of the `recently_deleted` undo stack, written alongside the config so
+/// soft-deleted projects survive a restart instead of being lost once the process exits.
+#[derive(Default, Serialize, Deserialize)]
+struct Trash {
+    entries: Vec<TrashEntry>
 }
 
 impl ProjectLibrary {
     pub fn from_file(file_path: &PathBuf) -> Result<Self> {
         let file_string = fs::read_to_string(file_path)?;
         let mut parsed_lib = toml::from_str::<ProjectLibrary>(&file_string)?;
+        parsed_lib.trash_file_path = file_path.with_file_name("trash.toml");
         parsed_lib.library_file_path = file_path.clone();
+        parsed_lib.recently_deleted = load_trash(&parsed_lib.trash_file_path)?;
         Ok(parsed_lib)
     }
 
-    pub fn save(&self) -> Result<()> {
+    pub fn save(&mut self) -> Result<()> {
         let to_string = toml::to_string_pretty(self)?;
         fs::write(&self.library_file_path, to_string)?;
+        self.save_trash()?;
+        self.last_saved_at = Some(Instant::now());
+        self.dirty = false;
+        Ok(())
+    }
+
+    /// Mirrors `recently_deleted` to `trash_file_path` so soft-deleted projects survive
+    /// a restart. Only entries still on the undo stack are written; the file is removed
+    /// when the stack is empty.
+    fn save_trash(&self) -> Result<()> {
+        if self.recently_deleted.is_empty() {
+            if self.trash_file_path.try_exists()? {
+                fs::remove_file(&self.trash_file_path)?;
+            }
+            return Ok(());
+        }
+
+        let trash = Trash {
+            entries: self.recently_deleted.iter()
+                .map(|(index, project)| TrashEntry { index: *index, project: project.clone() })
+                .collect()
+        };
+        fs::write(&self.trash_file_path, toml::to_string_pretty(&trash)?)?;
+        Ok(())
+    }
+
+    /// Re-reads `theme` from `library_file_path` unconditionally, and `projects` only if
+    /// nothing has changed in-session since the last `save` (`save` only runs on exit, so
+    /// an external edit arriving mid-session would otherwise silently discard unsaved
+    /// adds/deletes/status changes). Keeps the in-memory filter, status tab and
+    /// (best-effort) selection so an external edit doesn't reset what the user is
+    /// currently looking at.
+    pub fn reload_from_disk(&mut self) -> Result<()> {
+        let file_string = fs::read_to_string(&self.library_file_path)?;
+        let parsed_lib = toml::from_str::<ProjectLibrary>(&file_string)?;
+        self.theme = parsed_lib.theme;
+        if !self.dirty {
+            self.projects = parsed_lib.projects;
+        }
+        self.clamp_selected_project_index();
         Ok(())
     }
 
+    /// Whether `save` wrote to disk recently enough that a filesystem event for that
+    /// write might still be in flight, so it should be ignored instead of reloaded.
+    pub fn was_recently_saved(&self) -> bool {
+        self.last_saved_at.is_some_and(|at| at.elapsed() < Duration::from_millis(500))
+    }
+
     pub fn add_project(&mut self, project: Project) -> &Self {
         self.projects.push(project);
+        self.dirty = true;
         self
     }
 
     pub fn cycle_selected_project(&mut self, direction: CycleDirection) {
+        let visible_count = self.visible_projects().len();
+        if visible_count == 0 {
+            self.selected_project_index = 0;
+            return;
+        }
+
         match direction {
             CycleDirection::Down => {
-                if self.selected_project_index >= self.projects.len()-1 {
+                if self.selected_project_index >= visible_count-1 {
                     self.selected_project_index = 0;
                 } else {
                     self.selected_project_index += 1;
@@ -131,7 +333,7 @@ impl ProjectLibrary {
             },
             CycleDirection::Up => {
                 if self.selected_project_index == 0 {
-                    self.selected_project_index = self.projects.len() - 1;
+                    self.selected_project_index = visible_count - 1;
                 } else {
                     self.selected_project_index -= 1;
                 }
@@ -140,34 +342,187 @@ impl ProjectLibrary {
     }
 
     pub fn cycle_selected_project_status(&mut self, direction: CycleDirection) {
-        if let Some(project) = self.projects.get_mut(self.selected_project_index) {
-            project.cycle_status(direction);
+        let original_index = self.visible_projects().get(self.selected_project_index).map(|(index, ..)| *index);
+
+        if let Some(original_index) = original_index {
+            if let Some(project) = self.projects.get_mut(original_index) {
+                project.cycle_status(direction);
+                self.dirty = true;
+            }
+        }
+
+        self.clamp_selected_project_index();
+    }
+
+    pub fn has_selected_project(&self) -> bool {
+        self.selected_project_index < self.visible_projects().len()
+    }
+
+    /// Soft-deletes the selected project onto the `recently_deleted` undo stack rather
+    /// than dropping it outright, so an accidental delete can be reversed with `undo_delete`.
+    pub fn delete_selected_project(&mut self) {
+        let original_index = self.visible_projects().get(self.selected_project_index).map(|(index, ..)| *index);
+
+        let Some(original_index) = original_index else { return };
+        let removed_project = self.projects.remove(original_index);
+        self.recently_deleted.push((original_index, removed_project));
+        self.dirty = true;
+        self.clamp_selected_project_index();
+    }
+
+    /// Restores the most recently deleted project to its prior index, clamped to the
+    /// current length of `projects`. `selected_project_index` indexes `visible_projects`
+    /// (not `projects`), so the restored project is selected by looking its original
+    /// index back up in the visible list rather than reusing `insert_index` directly;
+    /// if the restored project doesn't match the active filter/status tab, the selection
+    /// is just clamped instead.
+    pub fn undo_delete(&mut self) {
+        let Some((original_index, project)) = self.recently_deleted.pop() else { return };
+        let insert_index = original_index.min(self.projects.len());
+        self.projects.insert(insert_index, project);
+        self.dirty = true;
+
+        match self.visible_projects().iter().position(|(index, ..)| *index == insert_index) {
+            Some(visible_index) => self.selected_project_index = visible_index,
+            None => self.clamp_selected_project_index()
         }
     }
+
+    pub fn has_recently_deleted(&self) -> bool {
+        !self.recently_deleted.is_empty()
+    }
+
+    pub fn push_filter_char(&mut self, c: char) {
+        self.filter_query.push(c);
+        self.clamp_selected_project_index();
+    }
+
+    pub fn pop_filter_char(&mut self) {
+        self.filter_query.pop();
+        self.clamp_selected_project_index();
+    }
+
+    pub fn clear_filter(&mut self) {
+        self.filter_query.clear();
+    }
+
+    pub fn filter_query(&self) -> &str {
+        &self.filter_query
+    }
+
+    pub fn cycle_status_filter(&mut self, direction: CycleDirection) {
+        let len = STATUS_FILTERS.len();
+        self.status_filter_index = match direction {
+            CycleDirection::Down => (self.status_filter_index + 1) % len,
+            CycleDirection::Up => (self.status_filter_index + len - 1) % len
+        };
+        self.clamp_selected_project_index();
+    }
+
+    pub fn status_filter_index(&self) -> usize {
+        self.status_filter_index
+    }
+
+    /// Tab labels for the status filter bar, e.g. "In Progress (3)", counted over the
+    /// whole library regardless of the active search query.
+    pub fn status_filter_labels(&self) -> Vec<String> {
+        STATUS_FILTERS.iter().map(|status_filter| {
+            let count = self.projects.iter()
+                .filter(|project| status_filter.is_none_or(|status| project.status == status))
+                .count();
+
+            let name = match status_filter {
+                None => "All",
+                Some(ProjectStatus::Finished) => "Finished",
+                Some(ProjectStatus::InProgress) => "In Progress",
+                Some(ProjectStatus::Idea) => "Idea",
+                Some(ProjectStatus::Paused) => "Paused"
+            };
+
+            format!("{} ({})", name, count)
+        }).collect()
+    }
+
+    fn clamp_selected_project_index(&mut self) {
+        let visible_count = self.visible_projects().len();
+        if self.selected_project_index >= visible_count {
+            self.selected_project_index = visible_count.saturating_sub(1);
+        }
+    }
+
+    /// Returns the projects that survive the current status filter and search query,
+    /// paired with their original index into `projects` and the indices of `name`
+    /// matched by the query, sorted by descending fuzzy-match score (unordered when
+    /// the query is empty).
+    fn visible_projects(&self) -> Vec<(usize, &Project, Vec<usize>)> {
+        let status_filter = STATUS_FILTERS[self.status_filter_index];
+        let status_matches = |project: &Project| status_filter.is_none_or(|status| project.status == status);
+
+        if self.filter_query.is_empty() {
+            return self.projects.iter().enumerate()
+                .filter(|(_, project)| status_matches(project))
+                .map(|(index, project)| (index, project, Vec::new()))
+                .collect();
+        }
+
+        let mut scored_matches: Vec<(usize, &Project, i64, Vec<usize>)> = self.projects.iter().enumerate()
+            .filter(|(_, project)| status_matches(project))
+            .filter_map(|(index, project)| {
+                fuzzy_match(&self.filter_query, &project.name)
+                    .map(|(score, matched_indices)| (index, project, score, matched_indices))
+            })
+            .collect();
+
+        scored_matches.sort_by_key(|(_, _, score, _)| std::cmp::Reverse(*score));
+
+        scored_matches.into_iter().map(|(index, project, _, matched_indices)| (index, project, matched_indices)).collect()
+    }
 }
 
 impl Widget for &ProjectLibrary {
     fn render(self, area: Rect, buf: &mut ratatui::prelude::Buffer) {
+        let outer_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Min(0)
+            ]).split(area);
+
+        let tabs = Tabs::new(self.status_filter_labels())
+            .select(self.status_filter_index)
+            .highlight_style(Style::default().fg(self.theme.selection_background).bold())
+            .block(Block::bordered().border_type(BorderType::Double).border_style(self.theme.border_color));
+        tabs.render(outer_chunks[0], buf);
+
         let chunks = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([
                 Constraint::Percentage(30),
                 Constraint::Percentage(70)
-            ]).split(area);
-        
-        let project_list_title = Title::from(" Projects ".bold());
+            ]).split(outer_chunks[1]);
+
+        let visible_projects = self.visible_projects();
+
+        let project_list_title_text = if self.filter_query.is_empty() {
+            " Projects ".to_string()
+        } else {
+            format!(" Projects  /{} ", self.filter_query)
+        };
+        let project_list_title = Title::from(project_list_title_text.bold());
         let project_list_block = Block::new()
                 .borders(Borders::TOP | Borders::LEFT | Borders::BOTTOM)
                 .title(project_list_title.alignment(Alignment::Left))
                 .padding(Padding::horizontal(1))
-                .border_type(BorderType::Double);
+                .border_type(BorderType::Double)
+                .border_style(self.theme.border_color);
+
+        let _ = build_project_list(&visible_projects, self.selected_project_index, &self.theme).block(project_list_block).render(chunks[0], buf);
 
-        let _ = build_project_list(&self.projects, self.selected_project_index).block(project_list_block).render(chunks[0], buf);
-        
         let project_details_title = Title::from(" Project Details ".bold());
         let project_details_block = Block::bordered()
                 .title(project_details_title.alignment(Alignment::Left))
                 .padding(Padding::horizontal(1))
+                .border_style(self.theme.border_color)
                 .border_set(
                     symbols::border::Set {
                         top_left: symbols::line::DOUBLE.horizontal_down,
@@ -176,28 +531,26 @@ impl Widget for &ProjectLibrary {
                     }
                 );
 
-        if self.selected_project_index < self.projects.len() {
-            let selected_project = &self.projects[self.selected_project_index];
-
-            let text = Text::from(vec![
+        if let Some((_, selected_project, _)) = visible_projects.get(self.selected_project_index) {
+            let mut text = Text::from(vec![
                 Line::from(Span::from(format!("Project Name: {}", selected_project.name))),
                 Line::from(Span::from(format!("Status: {}", selected_project.status))),
                 Line::from(Span::from("Description:")),
-                Line::from(Span::from(&selected_project.description)),
             ]);
+            text.extend(render_markdown(&selected_project.description));
 
-            let _ = Paragraph::new(text).block(project_details_block).render(chunks[1], buf);
+            let _ = Paragraph::new(text).wrap(Wrap { trim: false }).block(project_details_block).render(chunks[1], buf);
         } else {
             project_details_block.render(chunks[1], buf);
         }
     }
 }
 
-fn build_project_list(projects: &Vec<Project>, selected_project_index: usize) -> List {
-    let list_items = projects.iter().enumerate().map(|(index, project)| {
-        let item = project.to_list_item();
-        if index == selected_project_index { 
-            item.bold().bg(Color::Blue)
+fn build_project_list(visible_projects: &[(usize, &Project, Vec<usize>)], selected_project_index: usize, theme: &Theme) -> List<'static> {
+    let list_items = visible_projects.iter().enumerate().map(|(index, (_, project, matched_indices))| {
+        let item = project.to_list_item(matched_indices, theme);
+        if index == selected_project_index {
+            item.bold().bg(theme.selection_background)
         } else {
             item
         }
@@ -205,5 +558,57 @@ fn build_project_list(projects: &Vec<Project>, selected_project_index: usize) ->
     ).collect::<Vec<ListItem>>();
 
     List::new(list_items)
-        .highlight_style(Style::default().bg(Color::Blue).bold())
-}
\ No newline at end of file
+        .highlight_style(Style::default().bg(theme.selection_background).bold())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fuzzy_match_scores_contiguous_matches_higher_than_scattered_ones() {
+        let (contiguous_score, _) = fuzzy_match("ab", "abc").unwrap();
+        let (scattered_score, _) = fuzzy_match("ac", "abc").unwrap();
+        assert!(contiguous_score > scattered_score);
+    }
+
+    #[test]
+    fn fuzzy_match_scores_word_boundary_matches_higher_than_mid_word_ones() {
+        let (boundary_score, _) = fuzzy_match("b", "a_bc").unwrap();
+        let (mid_word_score, _) = fuzzy_match("b", "abc").unwrap();
+        assert!(boundary_score > mid_word_score);
+    }
+
+    #[test]
+    fn fuzzy_match_returns_none_when_query_is_not_a_subsequence() {
+        assert!(fuzzy_match("xyz", "abc").is_none());
+    }
+
+    fn library_with(statuses: &[ProjectStatus]) -> ProjectLibrary {
+        let mut library = ProjectLibrary::default();
+        for (index, status) in statuses.iter().enumerate() {
+            library.add_project(Project::new(&index.to_string(), ""));
+            library.projects[index].set_status(*status);
+        }
+        library
+    }
+
+    #[test]
+    fn undo_delete_restores_selection_within_the_active_status_filter() {
+        let mut library = library_with(&[ProjectStatus::Idea, ProjectStatus::Finished, ProjectStatus::Idea]);
+        library.status_filter_index = STATUS_FILTERS.iter()
+            .position(|status| *status == Some(ProjectStatus::Idea))
+            .unwrap();
+
+        // Visible (Idea-only) rows are "0" and "2"; select the second one.
+        library.selected_project_index = 1;
+        library.delete_selected_project();
+        assert_eq!(library.projects.len(), 2);
+
+        library.undo_delete();
+        assert_eq!(library.projects.len(), 3);
+
+        let visible_projects = library.visible_projects();
+        assert_eq!(visible_projects[library.selected_project_index].1.name, "2");
+    }
+}